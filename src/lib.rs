@@ -25,6 +25,9 @@ pub use weak::Weak;
 mod sym;
 pub use sym::Symbol;
 
+mod verify;
+pub use verify::VerifyError;
+
 use std::{io, path};
 
 pub use dylink_macro::dylink;
@@ -78,6 +81,67 @@ impl Library {
 	pub fn open<P: AsRef<path::Path>>(path: P) -> io::Result<Self> {
 		unsafe { imp::InnerLibrary::open(path.as_ref().as_os_str()) }.map(Self)
 	}
+
+	/// Attempts to open a dynamic library file after the contents of the file have been accepted by `verifier`.
+	///
+	/// The file is read in full and its bytes are passed to `verifier`. The library is only loaded if
+	/// `verifier` returns `true`, which makes this a convenient hook for hash or signature checking.
+	/// Unlike [`open`], `path` must be absolute; relative paths and bare library names are rejected
+	/// because the system search paths could resolve them to a file that was never verified.
+	///
+	/// [`open`]: Library::open
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if `path` is not absolute.
+	///
+	/// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) carrying a [`VerifyError`]
+	/// if `verifier` rejects the file, or if the file was replaced between verification and loading.
+	///
+	/// # Security
+	///
+	/// On Windows and unix platforms other than Linux, if a library with the same path, file, or module
+	/// name is already loaded, the system returns a handle to that library instead, and the verified bytes
+	/// are never mapped. The check therefore only covers libraries that are not yet loaded into the process.
+	///
+	/// # Platform-specific Behavior
+	///
+	/// On Linux the verified bytes are copied into a sealed anonymous file which the library is
+	/// loaded from, so neither replacing nor modifying the file at `path` affects what is mapped.
+	/// Every call therefore maps a new copy of the library, even if the same file is already loaded.
+	/// [`Image::path`](img::Image::path) reports such a library as `/proc/self/fd/<fd>`, which no
+	/// longer refers to it once this function returns.
+	///
+	/// On Windows the file is opened without write or delete sharing, so it cannot be modified until
+	/// the library is loaded.
+	///
+	/// On other unix platforms the file identity is checked again right before loading. This leaves a
+	/// window between the check and the load in which the file can be replaced, and modifications made
+	/// to the file in place cannot be detected.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use dylink::Library;
+	///
+	/// let lib = Library::open_verified("C:\\plugins\\foo.dll", |data| data.starts_with(b"MZ")).unwrap();
+	/// ```
+	#[doc(alias = "dlopen", alias = "LoadLibrary")]
+	pub fn open_verified<P, F>(path: P, verifier: F) -> io::Result<Self>
+	where
+		P: AsRef<path::Path>,
+		F: Fn(&[u8]) -> bool,
+	{
+		let path = path.as_ref();
+		if !path.is_absolute() {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				"library path must be absolute",
+			));
+		}
+		unsafe { imp::InnerLibrary::open_verified(path.as_os_str(), &verifier) }.map(Self)
+	}
+
 	/// Attempts to return a library handle to the current process.
 	///
 	/// # Panics
@@ -1,10 +1,17 @@
 #![allow(clippy::let_unit_value)]
 
 use crate::sealed::Sealed;
-use crate::{img, weak, Symbol};
+use crate::{img, weak, Symbol, VerifyError};
 #[cfg(unix)]
 use std::os::unix::ffi::OsStrExt;
-use std::{ffi, io, mem, path::PathBuf, ptr};
+#[cfg(not(target_os = "linux"))]
+use std::os::unix::fs::MetadataExt;
+use std::{ffi, fs, io, io::Read, mem, path::PathBuf, ptr};
+#[cfg(target_os = "linux")]
+use std::{
+	io::Write,
+	os::unix::io::{AsRawFd, FromRawFd},
+};
 
 #[cfg(target_os = "macos")]
 use std::sync::{
@@ -63,6 +70,64 @@ impl InnerLibrary {
 			Err(io::Error::new(io::ErrorKind::Other, err.to_string_lossy()))
 		}
 	}
+	pub unsafe fn open_verified(
+		path: &ffi::OsStr,
+		verifier: &dyn Fn(&[u8]) -> bool,
+	) -> io::Result<Self> {
+		let mut file = fs::File::open(path)?;
+		let mut data = Vec::new();
+		file.read_to_end(&mut data)?;
+		if !verifier(&data) {
+			return Err(VerifyError::Rejected.into());
+		}
+		Self::open_file(path, file, &data)
+	}
+
+	// The verified bytes are loaded from a sealed memfd, so neither the path nor the
+	// file contents can change what gets mapped.
+	#[cfg(target_os = "linux")]
+	unsafe fn open_file(_path: &ffi::OsStr, _file: fs::File, data: &[u8]) -> io::Result<Self> {
+		let fd = c::memfd_create(c"dylink".as_ptr(), c::MFD_CLOEXEC | c::MFD_ALLOW_SEALING);
+		if fd == -1 {
+			return Err(io::Error::last_os_error());
+		}
+		let mut memfd = fs::File::from_raw_fd(fd);
+		memfd.write_all(data)?;
+		let seals = c::F_SEAL_WRITE | c::F_SEAL_SHRINK | c::F_SEAL_GROW;
+		if c::fcntl(memfd.as_raw_fd(), c::F_ADD_SEALS, seals) == -1 {
+			return Err(io::Error::last_os_error());
+		}
+		// dlopen matches libraries by name first, so a library loaded earlier through a
+		// descriptor with the same number would be returned instead. Duplicate the
+		// descriptor until its path is unused.
+		let mut fds = vec![memfd];
+		loop {
+			let fd_path =
+				ffi::CString::new(format!("/proc/self/fd/{}", fds[fds.len() - 1].as_raw_fd()))?;
+			let loaded = c::dlopen(
+				fd_path.as_ptr(),
+				c::RTLD_NOW | c::RTLD_LOCAL | c::RTLD_NOLOAD,
+			);
+			if loaded.is_null() {
+				// the descriptors are closed on return, after the library is mapped.
+				return Self::open(ffi::OsStr::from_bytes(fd_path.as_bytes()));
+			}
+			let _ = c::dlclose(loaded);
+			fds.push(fds[0].try_clone()?);
+		}
+	}
+
+	// There is no descriptor based loading here, so make sure the path still refers to the
+	// verified file. The file may still be replaced between this check and `dlopen`.
+	#[cfg(not(target_os = "linux"))]
+	unsafe fn open_file(path: &ffi::OsStr, file: fs::File, _data: &[u8]) -> io::Result<Self> {
+		let (verified, current) = (file.metadata()?, fs::metadata(path)?);
+		if verified.dev() != current.dev() || verified.ino() != current.ino() {
+			return Err(VerifyError::Replaced.into());
+		}
+		Self::open(path)
+	}
+
 	pub unsafe fn this() -> io::Result<Self> {
 		let _lock = dylib_guard();
 		let handle: *mut ffi::c_void = c::dlopen(ptr::null(), c::RTLD_NOW | c::RTLD_LOCAL);
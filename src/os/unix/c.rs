@@ -43,8 +43,20 @@ pub struct Dl_info {
 
 pub const RTLD_LOCAL: ffi::c_int = 0;
 pub const RTLD_NOW: ffi::c_int = 0x2;
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
 pub const RTLD_NOLOAD: ffi::c_int = 0x4;
+#[cfg(target_os = "linux")]
+pub const MFD_CLOEXEC: ffi::c_uint = 0x1;
+#[cfg(target_os = "linux")]
+pub const MFD_ALLOW_SEALING: ffi::c_uint = 0x2;
+#[cfg(target_os = "linux")]
+pub const F_ADD_SEALS: ffi::c_int = 1033;
+#[cfg(target_os = "linux")]
+pub const F_SEAL_SHRINK: ffi::c_int = 0x2;
+#[cfg(target_os = "linux")]
+pub const F_SEAL_GROW: ffi::c_int = 0x4;
+#[cfg(target_os = "linux")]
+pub const F_SEAL_WRITE: ffi::c_int = 0x8;
 #[cfg(target_env = "gnu")]
 pub const RTLD_DI_LINKMAP: ffi::c_int = 2;
 #[cfg(target_env = "gnu")]
@@ -164,6 +176,8 @@ pub type DlIteratePhdrCallback = unsafe extern "C" fn(
 #[cfg(target_os = "linux")]
 extern "C" {
 	pub fn dl_iterate_phdr(callback: DlIteratePhdrCallback, data: *mut ffi::c_void) -> ffi::c_int;
+	pub fn memfd_create(name: *const ffi::c_char, flags: ffi::c_uint) -> ffi::c_int;
+	pub fn fcntl(fd: ffi::c_int, cmd: ffi::c_int, ...) -> ffi::c_int;
 }
 
 #[cfg(target_os = "macos")]
@@ -2,11 +2,11 @@ use std::os::windows::ffi::{OsStrExt, OsStringExt};
 
 use std::os::windows::prelude::*;
 use std::path::PathBuf;
-use std::{ffi, io, mem, path, ptr};
+use std::{ffi, fs, io, io::Read, mem, path, ptr};
 
 use crate::img;
 use crate::weak;
-use crate::{Library, Symbol};
+use crate::{Library, Symbol, VerifyError};
 
 mod c;
 
@@ -27,6 +27,23 @@ impl InnerLibrary {
 			.map(Self)
 	}
 
+	pub unsafe fn open_verified(
+		path: &ffi::OsStr,
+		verifier: &dyn Fn(&[u8]) -> bool,
+	) -> io::Result<Self> {
+		// deny write and delete sharing so the file cannot change until it's loaded.
+		let mut file = fs::OpenOptions::new()
+			.read(true)
+			.share_mode(c::FILE_SHARE_READ)
+			.open(path)?;
+		let mut data = Vec::new();
+		file.read_to_end(&mut data)?;
+		if !verifier(&data) {
+			return Err(VerifyError::Rejected.into());
+		}
+		Self::open(path)
+	}
+
 	pub unsafe fn this() -> io::Result<Self> {
 		let mut handle: *mut ffi::c_void = ptr::null_mut();
 		c::GetModuleHandleExW(0, ptr::null(), &mut handle);
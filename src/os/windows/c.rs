@@ -273,6 +273,7 @@ pub const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: DWORD = 0x00000002u32;
 pub const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: DWORD = 0x00000004u32;
 
 pub const LIST_MODULES_ALL: DWORD = 0x03;
+pub const FILE_SHARE_READ: DWORD = 0x00000001u32;
 pub const IMAGE_SIZEOF_SHORT_NAME: usize = 8;
 
 #[repr(C)]
@@ -0,0 +1,40 @@
+use std::{error, fmt, io};
+
+/// The reason a library was refused by [`Library::open_verified`](crate::Library::open_verified).
+///
+/// This is carried as the inner error of an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData),
+/// so it can be told apart from other errors of that kind.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dylink::{Library, VerifyError};
+///
+/// let err = Library::open_verified("/usr/lib/libfoo.so", |_| false).unwrap_err();
+/// let reason = err.get_ref().and_then(|e| e.downcast_ref::<VerifyError>());
+/// assert_eq!(reason, Some(&VerifyError::Rejected));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+	/// The verifier rejected the contents of the file.
+	Rejected,
+	/// The path no longer refers to the file that was verified.
+	Replaced,
+}
+
+impl fmt::Display for VerifyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Rejected => f.write_str("library verification failed"),
+			Self::Replaced => f.write_str("library was replaced after verification"),
+		}
+	}
+}
+
+impl error::Error for VerifyError {}
+
+impl From<VerifyError> for io::Error {
+	fn from(value: VerifyError) -> Self {
+		io::Error::new(io::ErrorKind::InvalidData, value)
+	}
+}
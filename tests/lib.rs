@@ -102,3 +102,23 @@ fn test_downgrade_upgrade() {
 
 	assert!(strong_clone.is_some());
 }
+
+#[test]
+fn test_open_verified_rejected() {
+	let path = std::env::current_exe().unwrap();
+	let len = std::fs::metadata(&path).unwrap().len() as usize;
+	let result = Library::open_verified(&path, |data| {
+		assert_eq!(data.len(), len);
+		false
+	});
+	let err = result.unwrap_err();
+	assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+	let reason = err.get_ref().and_then(|e| e.downcast_ref::<VerifyError>());
+	assert_eq!(reason, Some(&VerifyError::Rejected));
+}
+
+#[test]
+fn test_open_verified_relative() {
+	let result = Library::open_verified("foo", |_| panic!("relative path was verified"));
+	assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+}
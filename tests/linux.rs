@@ -1,5 +1,8 @@
 #![cfg(target_os = "linux")]
 use dylink::*;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
 
 static LIB_X11: sync::LibLock = sync::LibLock::new(&["libX11.so.6"]);
 
@@ -53,3 +56,91 @@ fn test_path() {
 	let path = lib.to_image().unwrap().path();
 	assert!(path.is_ok())
 }
+
+// Copies the file that `lib` was loaded from to `dest`.
+fn copy_lib(lib: &str, dest: &Path) {
+	let lib = Library::open(lib).unwrap();
+	let src = lib.to_image().unwrap().path().unwrap();
+	fs::copy(src, dest).unwrap();
+}
+
+// A temporary directory that is removed when dropped, even if the test fails.
+struct TempDir(PathBuf);
+
+impl TempDir {
+	fn new(name: &str) -> Self {
+		let dir = env::temp_dir().join(format!("dylink-{}-{name}", process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		Self(dir)
+	}
+}
+
+impl Drop for TempDir {
+	fn drop(&mut self) {
+		let _ = fs::remove_dir_all(&self.0);
+	}
+}
+
+#[test]
+fn test_open_verified() {
+	let lib = Library::open("libX11.so.6").unwrap();
+	let path = lib.to_image().unwrap().path().unwrap();
+	let lib = Library::open_verified(path, |data| data.starts_with(b"\x7fELF"));
+	assert!(lib.is_ok())
+}
+
+#[test]
+fn test_open_verified_search_path() {
+	// a different library under the name of one on the search path.
+	let dir = TempDir::new("search-path");
+	let path = dir.0.join("libX11.so.6");
+	copy_lib("libxcb.so.1", &path);
+	let verified = RefCell::new(Vec::new());
+	let lib = Library::open_verified(&path, |data| {
+		verified.replace(data.to_vec());
+		true
+	})
+	.unwrap();
+	let hdr = lib.to_image().unwrap().to_bytes().unwrap();
+	assert_eq!(hdr, &verified.borrow()[..hdr.len()]);
+	let x11 = Library::open("libX11.so.6").unwrap();
+	assert_ne!(hdr, x11.to_image().unwrap().to_bytes().unwrap());
+}
+
+#[test]
+fn test_open_verified_replaced() {
+	let dir = TempDir::new("replaced");
+	let path = dir.0.join("libdylink_replaced.so");
+	let other = dir.0.join("libdylink_other.so");
+	copy_lib("libxcb.so.1", &path);
+	copy_lib("libX11.so.6", &other);
+	let verified = RefCell::new(Vec::new());
+	let lib = Library::open_verified(&path, |data| {
+		verified.replace(data.to_vec());
+		// swap the file out after it has been read.
+		fs::rename(&other, &path).unwrap();
+		true
+	})
+	.unwrap();
+	let hdr = lib.to_image().unwrap().to_bytes().unwrap();
+	assert_eq!(hdr, &verified.borrow()[..hdr.len()]);
+}
+
+#[test]
+fn test_open_verified_modified() {
+	let dir = TempDir::new("modified");
+	let path = dir.0.join("libdylink_modified.so");
+	copy_lib("libxcb.so.1", &path);
+	let other = Library::open("libX11.so.6").unwrap();
+	let other = other.to_image().unwrap().path().unwrap();
+	let verified = RefCell::new(Vec::new());
+	let lib = Library::open_verified(&path, |data| {
+		verified.replace(data.to_vec());
+		// overwrite the file in place after it has been read.
+		fs::write(&path, fs::read(&other).unwrap()).unwrap();
+		true
+	})
+	.unwrap();
+	let hdr = lib.to_image().unwrap().to_bytes().unwrap();
+	assert_eq!(hdr, &verified.borrow()[..hdr.len()]);
+}